@@ -1,333 +1,1002 @@
-//! This crate provides a `Matrix` structure, with many helpful
-//! trait implementations to perform calculations between
-//! matrices, but with absolutely no allocations.
-
-use std::{fmt, ops};
-
-/// The identity matrix `2x2`.
-pub const I_2: Matrix<2, 2> = Matrix {
-    body: [
-        [1.0, 0.0],
-        [0.0, 1.0],
-    ]
-};
-
-/// The identity matrix `3x3`.
-pub const I_3: Matrix<3, 3> = Matrix {
-    body: [
-        [1.0, 0.0, 0.0],
-        [0.0, 1.0, 0.0],
-        [0.0, 0.0, 1.0],
-    ]
-};
-
-/// The identity matrix `4x4`.
-pub const I_4: Matrix<4, 4> = Matrix {
-    body: [
-        [1.0, 0.0, 0.0, 0.0],
-        [0.0, 1.0, 0.0, 0.0],
-        [0.0, 0.0, 1.0, 0.0],
-        [0.0, 0.0, 0.0, 1.0],
-    ]
-};
-
-/// The `90°` rotation matrix `2x2`.
-pub const R90_2: Matrix<2, 2> = Matrix {
-    body: [
-        [0.0, -1.0],
-        [1.0,  0.0],
-    ]
-};
-
-/// The `180°` rotation matrix `2x2`.
-pub const R180_2: Matrix<2, 2> = Matrix {
-    body: [
-        [-1.0,  0.0],
-        [ 0.0, -1.0],
-    ]
-};
-
-/// The `270°` rotation matrix `2x2`.
-pub const R270_2: Matrix<2, 2> = Matrix {
-    body: [
-        [ 0.0, 1.0],
-        [-1.0, 0.0]
-    ]
-};
-
-/// A struct that represents a Matrix
-/// with `M` rows and `N` columns.
-/// 
-/// # Examples
-/// 
-/// ```
-/// # pub use small_matrix::matrix::Matrix;
-/// let matrix = Matrix::new([
-///     [2.0, 3.0],
-///     [5.0, 8.0],
-///     [7.0, 9.0]
-/// ]);
-/// ```
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct Matrix<const M: usize, const N: usize> {
-    body: [[f32; N]; M],
-}
-
-impl<const M: usize, const N: usize> Matrix<M, N> {
-    /// Returns a new matrix based on
-    /// the given array of [[f32; N]; M].
-    /// 
-    /// # Examples
-    /// 
-    /// ```
-    /// # pub use small_matrix::matrix::Matrix;
-    /// let matrix = Matrix::new([
-    ///     [-1.1, 4.2],
-    ///     [2.4, 3.6],
-    /// ]);
-    /// ```
-    pub fn new(body: [[f32; N]; M]) -> Self {
-        Self { body }
-    }
-
-    /// Returns a matrix with the given
-    /// dimensions with `0.0`s.
-    /// 
-    /// # Examples
-    /// 
-    /// ```
-    /// # pub use small_matrix::matrix::Matrix;
-    /// let zeros = Matrix::<5, 4>::zeros(); // fills the matrix with zeros
-    /// ```
-    pub fn zeros() -> Self {
-        Self {
-            body: [[0.0; N]; M]
-        }
-    }
-
-    /// Returns a matrix with the given
-    /// dimensions with `n`s.
-    /// 
-    /// # Examples
-    /// 
-    /// ```
-    /// # pub use small_matrix::matrix::Matrix;
-    /// let threes = Matrix::<3, 2>::fill(3.0); // fills the matrix with threes
-    /// ```
-    pub fn fill(n: f32) -> Self {
-        Self {
-            body: [[n; N]; M]
-        }
-    }
-
-    /// Returns the size of the matrix, `(M, N)`.
-    /// 
-    /// # Examples
-    /// 
-    /// ```
-    /// # pub use small_matrix::matrix::Matrix;
-    /// let matrix = Matrix::<3, 5>::zeros(); // fills the matrix with zeros
-    /// 
-    /// assert_eq!(matrix.size(), (3, 5));
-    /// ```
-    pub fn size(&self) -> (usize, usize) {
-        (M, N)
-    }
-
-    /// Returns an `Option<f32>`, with the element placed on the
-    /// `pos.1`-nth column, on the `pos.0`-nth row, if
-    /// `pos.0` is less than `M` and `pos.1` is less than `N`.
-    /// 
-    /// # Examples
-    /// 
-    /// ```
-    /// # pub use small_matrix::matrix::Matrix;
-    /// let matrix = Matrix::new([
-    ///     [1.0, 2.0],
-    ///     [3.0, 4.0],
-    /// ]);
-    /// 
-    /// assert_eq!(matrix.get((0, 1)).unwrap(), 2.0); // first row, second column
-    /// ```
-    pub fn get(&self, pos: (usize, usize)) -> Option<f32> {
-        if pos.0 < M && pos.1 < N {
-            Some(self.body[pos.0][pos.1])
-        } else {
-            None
-        }
-    }
-
-    /// Returns the given matrix transposed.
-    /// 
-    /// # Examples
-    /// 
-    /// ```
-    /// # pub use small_matrix::matrix::Matrix;
-    /// // this is a `2x3` matrix
-    /// let matrix = Matrix::new([
-    ///     [1.2, 3.4, 7.3],
-    ///     [3.6, 9.4, 0.6],
-    /// ]);
-    /// 
-    /// // this is a `3x2` matrix
-    /// let transposed = matrix.transpose();
-    /// 
-    /// assert_eq!(transposed.size(), (3, 2));
-    /// ```
-    pub fn transpose(&self) -> Matrix<N, M> {
-        let mut body = [[0.0; M]; N];
-
-        body.iter_mut().enumerate().for_each(|(c, row)| {
-            row.iter_mut().enumerate().for_each(|(r, e)| *e = self.get((r, c)).unwrap())
-        });
-
-        Matrix { body }
-    }
-
-    /// Swaps the rows with the corresponding given indexes.
-    /// 
-    /// # Panics
-    /// 
-    /// Panics if `idx_1` or `idx_2` are out of bounds.
-    /// 
-    /// # Examples
-    /// 
-    /// ```
-    /// # pub use small_matrix::matrix::Matrix;
-    /// let mut matrix = Matrix::new([
-    ///     [1.0, 3.0],
-    ///     [2.0, 4.0],
-    /// ]);
-    /// 
-    /// matrix.swap_rows(0, 1); // swaps the first and the second row
-    /// 
-    /// assert_eq!(matrix.get((0, 0)).unwrap(), 2.0); // now the two rows are swapped
-    /// ```
-    pub fn swap_rows(&mut self, idx_1: usize, idx_2: usize) {
-        self.body.swap(idx_1, idx_2);
-    }
-
-    /// Applies the given function to every
-    /// element of the matrix.
-    /// 
-    /// # Examples
-    /// 
-    /// ```
-    /// # pub use small_matrix::matrix::Matrix;
-    /// let mut fives = Matrix::<2, 4>::fill(5.0); // fills the matrix with fives
-    /// 
-    /// fives.for_each(|element| *element += 2.0);
-    /// 
-    /// assert_eq!(fives.get((0, 0)).unwrap(), 7.0); // every element is now `7.0`
-    /// ```
-    pub fn for_each<F: FnMut(&mut f32)>(&mut self, mut function: F) {
-        self.body.iter_mut().for_each(|row| row.iter_mut().for_each(|e| function(e)));
-    }
-}
-
-impl<const M: usize, const N: usize> fmt::Display for Matrix<M, N> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.body.iter().try_for_each(|row| writeln!(f, "{:?}", row))
-    }
-}
-
-/// A macro used to implement `Add` and `Sub`.
-macro_rules! impl_ops {
-    ($trait:ident, $func:ident, $op:tt) => {
-        impl<const M: usize, const N: usize> ops::$trait for Matrix<M, N> {
-            type Output = Self;
-
-            fn $func(self, other: Self) -> Self {
-                let mut body = [[0.0; N]; M];
-                
-                body.iter_mut().zip(self.body.iter().zip(&other.body)).for_each(|(rr, (rs, ro))| {
-                    rr.iter_mut().zip(rs.iter().zip(ro)).for_each(|(r, (s, o))| *r = s $op o);
-                });
-
-                Self { body }
-            }
-        }
-    };
-}
-
-impl_ops!(Add, add, +);
-impl_ops!(Sub, sub, -);
-
-impl<const M: usize, const L: usize, const N: usize> ops::Mul<Matrix<L, N>> for Matrix<M, L> {
-    type Output = Matrix<M, N>;
-    
-    fn mul(self, other: Matrix<L, N>) -> Matrix<M, N> {
-        let mut body = [[0.0; N]; M];
-
-        let other_t = other.transpose();
-
-        body.iter_mut().zip(&self.body).for_each(|(rr, rs)| {
-            rr.iter_mut().zip(&other_t.body).for_each(|(r, ro)| {
-                *r = rs.iter().zip(ro).fold(0.0, |acc, (s, o)| acc + s * o);
-            });
-        });
-
-        Matrix { body }
-    }
-}
-
-/// A macro used to implement
-/// `AddAssign` and `SubAssign`.
-macro_rules! impl_ops_assign {
-    ($trait_assign:ident, $func_assign:ident, $op_assign:tt) => {
-        impl<const M: usize, const N: usize> ops::$trait_assign for Matrix<M, N> {
-            fn $func_assign(&mut self, other: Self) {
-                self.body.iter_mut().zip(&other.body).for_each(|(rs, ro)| {
-                    rs.iter_mut().zip(ro).for_each(|(s, o)| *s $op_assign o)
-                });
-            }
-        }
-    };
-}
-
-impl_ops_assign!(AddAssign, add_assign, +=);
-impl_ops_assign!(SubAssign, sub_assign, -=);
-
-/// A macro used to implement
-/// `Add<f32>`, `Sub<f32>`,
-/// `Mul<f32>` and `Div<f32>`.
-macro_rules! impl_opsf32 {
-    ($trait:ident, $func:ident, $op:tt) => {
-        impl<const M: usize, const N: usize> ops::$trait<f32> for Matrix<M, N> {
-            type Output = Self;
-
-            fn $func(self, other: f32) -> Self {
-                let mut body = [[0.0; N]; M];
-
-                body.iter_mut().zip(&self.body).for_each(|(rr, rs)| {
-                    rr.iter_mut().zip(rs).for_each(|(b, s)| *b = s $op other)
-                });
-                
-                Self { body }
-            }
-        }
-    };
-}
-
-impl_opsf32!(Add, add, +);
-impl_opsf32!(Sub, sub, -);
-impl_opsf32!(Mul, mul, *);
-impl_opsf32!(Div, div, /);
-
-/// A macro used to implement
-/// `AddAssign<f32>`, `SubAssign<f32>`,
-/// `MulAssign<f32>` and `DivAssign<f32>`.
-macro_rules! impl_ops_assignf32 {
-    ($trait_assign:ident, $func_assign:ident, $op_assign:tt) => {
-        impl<const M: usize, const N: usize> ops::$trait_assign<f32> for Matrix<M, N> {
-            fn $func_assign(&mut self, other: f32) {
-                self.body.iter_mut().for_each(|row| row.iter_mut().for_each(|e| *e $op_assign other));
-            }
-        }
-    };
-}
-
-impl_ops_assignf32!(AddAssign, add_assign, +=);
-impl_ops_assignf32!(SubAssign, sub_assign, -=);
-impl_ops_assignf32!(MulAssign, mul_assign, *=);
-impl_ops_assignf32!(DivAssign, div_assign, /=);
\ No newline at end of file
+//! This crate provides a `Matrix` structure, with many helpful
+//! trait implementations to perform calculations between
+//! matrices, but with absolutely no allocations.
+
+use std::{fmt, ops};
+
+/// A trait for the scalar types that can fill a [`Matrix`].
+///
+/// Implemented for `f32`, `f64`, and the built-in integer types,
+/// so that a `Matrix` can be built over any of them instead of
+/// being hard-coded to `f32`.
+pub trait Scalar:
+    Copy
+    + PartialEq
+    + ops::Add<Output = Self>
+    + ops::Sub<Output = Self>
+    + ops::Mul<Output = Self>
+{
+    /// Returns the additive identity, `0`.
+    fn zero() -> Self;
+
+    /// Returns the multiplicative identity, `1`.
+    fn one() -> Self;
+
+    /// Returns the threshold below which a value is considered
+    /// equal to [`Scalar::zero`]. `0` by default, since most
+    /// `Scalar` types have no rounding error to tolerate; only
+    /// [`Float`] types, which drive the pivoting in [`Matrix::lu`],
+    /// need to override it.
+    fn epsilon() -> Self {
+        Self::zero()
+    }
+}
+
+/// A macro used to implement [`Scalar`] for a list of types.
+macro_rules! impl_scalar {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Scalar for $ty {
+                fn zero() -> Self {
+                    0 as Self
+                }
+
+                fn one() -> Self {
+                    1 as Self
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl Scalar for f32 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn one() -> Self {
+        1.0
+    }
+
+    fn epsilon() -> Self {
+        1e-6
+    }
+}
+
+impl Scalar for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn one() -> Self {
+        1.0
+    }
+
+    fn epsilon() -> Self {
+        1e-12
+    }
+}
+
+/// A [`Scalar`] that also supports division, negation and a
+/// square root, needed for vector norms and for the `LU`-based
+/// numerics on square matrices. Implemented for `f32` and `f64`
+/// only: `det`/`inverse`/`solve` rely on true (non-truncating)
+/// division, which integer types can't provide.
+pub trait Float: Scalar + PartialOrd + ops::Neg<Output = Self> + ops::Div<Output = Self> {
+    /// Returns the square root of `self`.
+    fn sqrt(self) -> Self;
+}
+
+impl Float for f32 {
+    fn sqrt(self) -> Self {
+        f32::sqrt(self)
+    }
+}
+
+impl Float for f64 {
+    fn sqrt(self) -> Self {
+        f64::sqrt(self)
+    }
+}
+
+/// A trait for types that can address a single element of a
+/// `Matrix<T, M, N>` as `(row, column)` coordinates.
+///
+/// `usize` deliberately does not implement this trait: a bare
+/// `usize` already addresses a whole row via [`ops::Index<usize>`]
+/// (`matrix[i]`), so giving it a second, conflicting meaning here
+/// (a linear element offset) would make `matrix.get(i)` and
+/// `matrix[i]` disagree on what `i` means.
+pub trait Index2D {
+    /// Converts `self` into `(row, column)` coordinates, returning
+    /// `None` if they fall outside a matrix of the given `height`
+    /// and `width`.
+    fn to_2d(self, height: usize, width: usize) -> Option<(usize, usize)>;
+}
+
+impl Index2D for (usize, usize) {
+    fn to_2d(self, height: usize, width: usize) -> Option<(usize, usize)> {
+        (self.0 < height && self.1 < width).then_some(self)
+    }
+}
+
+/// A struct that represents a Matrix
+/// with `M` rows and `N` columns, over
+/// a scalar type `T`.
+///
+/// # Examples
+///
+/// ```
+/// # pub use small_matrix::matrix::Matrix;
+/// let matrix = Matrix::new([
+///     [2.0, 3.0],
+///     [5.0, 8.0],
+///     [7.0, 9.0]
+/// ]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Matrix<T: Scalar, const M: usize, const N: usize> {
+    body: [[T; N]; M],
+}
+
+impl<T: Scalar, const M: usize, const N: usize> Matrix<T, M, N> {
+    /// Returns a new matrix based on
+    /// the given array of [[T; N]; M].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # pub use small_matrix::matrix::Matrix;
+    /// let matrix = Matrix::new([
+    ///     [-1.1, 4.2],
+    ///     [2.4, 3.6],
+    /// ]);
+    /// ```
+    pub fn new(body: [[T; N]; M]) -> Self {
+        Self { body }
+    }
+
+    /// Returns a matrix with the given
+    /// dimensions filled with zeros.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # pub use small_matrix::matrix::Matrix;
+    /// let zeros = Matrix::<f32, 5, 4>::zeros(); // fills the matrix with zeros
+    /// ```
+    pub fn zeros() -> Self {
+        Self {
+            body: [[T::zero(); N]; M]
+        }
+    }
+
+    /// Returns a matrix with the given
+    /// dimensions with `n`s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # pub use small_matrix::matrix::Matrix;
+    /// let threes = Matrix::<f32, 3, 2>::fill(3.0); // fills the matrix with threes
+    /// ```
+    pub fn fill(n: T) -> Self {
+        Self {
+            body: [[n; N]; M]
+        }
+    }
+
+    /// Returns the size of the matrix, `(M, N)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # pub use small_matrix::matrix::Matrix;
+    /// let matrix = Matrix::<f32, 3, 5>::zeros(); // fills the matrix with zeros
+    ///
+    /// assert_eq!(matrix.size(), (3, 5));
+    /// ```
+    pub fn size(&self) -> (usize, usize) {
+        (M, N)
+    }
+
+    /// Returns an `Option<T>`, with the element at the `(row, column)`
+    /// coordinates `idx`, or `None` if `idx` falls outside the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # pub use small_matrix::matrix::Matrix;
+    /// let matrix = Matrix::new([
+    ///     [1.0, 2.0],
+    ///     [3.0, 4.0],
+    /// ]);
+    ///
+    /// assert_eq!(matrix[(0, 1)], 2.0); // first row, second column
+    /// ```
+    pub fn get<I: Index2D>(&self, idx: I) -> Option<T> {
+        idx.to_2d(M, N).map(|(r, c)| self.body[r][c])
+    }
+
+    /// Returns a mutable reference to the element addressed by
+    /// `idx`, or `None` if `idx` falls outside the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # pub use small_matrix::matrix::Matrix;
+    /// let mut matrix = Matrix::new([
+    ///     [1.0, 2.0],
+    ///     [3.0, 4.0],
+    /// ]);
+    ///
+    /// matrix[(0, 1)] = 7.0;
+    ///
+    /// assert_eq!(matrix[(0, 1)], 7.0);
+    /// ```
+    pub fn get_mut<I: Index2D>(&mut self, idx: I) -> Option<&mut T> {
+        idx.to_2d(M, N).map(move |(r, c)| &mut self.body[r][c])
+    }
+
+    /// Returns the given matrix transposed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # pub use small_matrix::matrix::Matrix;
+    /// // this is a `2x3` matrix
+    /// let matrix = Matrix::new([
+    ///     [1.2, 3.4, 7.3],
+    ///     [3.6, 9.4, 0.6],
+    /// ]);
+    ///
+    /// // this is a `3x2` matrix
+    /// let transposed = matrix.transpose();
+    ///
+    /// assert_eq!(transposed.size(), (3, 2));
+    /// ```
+    pub fn transpose(&self) -> Matrix<T, N, M> {
+        let mut transposed = Matrix::<T, N, M>::zeros();
+
+        self.indices().for_each(|(r, c)| transposed.body[c][r] = self.body[r][c]);
+
+        transposed
+    }
+
+    /// Swaps the rows with the corresponding given indexes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx_1` or `idx_2` are out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # pub use small_matrix::matrix::Matrix;
+    /// let mut matrix = Matrix::new([
+    ///     [1.0, 3.0],
+    ///     [2.0, 4.0],
+    /// ]);
+    ///
+    /// matrix.swap_rows(0, 1); // swaps the first and the second row
+    ///
+    /// assert_eq!(matrix[(0, 0)], 2.0); // now the two rows are swapped
+    /// ```
+    pub fn swap_rows(&mut self, idx_1: usize, idx_2: usize) {
+        self.body.swap(idx_1, idx_2);
+    }
+
+    /// Applies the given function to every
+    /// element of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # pub use small_matrix::matrix::Matrix;
+    /// let mut fives = Matrix::<f32, 2, 4>::fill(5.0); // fills the matrix with fives
+    ///
+    /// fives.for_each(|element| *element += 2.0);
+    ///
+    /// assert_eq!(fives[(0, 0)], 7.0); // every element is now `7.0`
+    /// ```
+    pub fn for_each<F: FnMut(&mut T)>(&mut self, function: F) {
+        self.iter_mut().for_each(function);
+    }
+
+    /// Returns an iterator over the elements of the matrix, in
+    /// row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # pub use small_matrix::matrix::Matrix;
+    /// let matrix = Matrix::new([
+    ///     [1.0, 2.0],
+    ///     [3.0, 4.0],
+    /// ]);
+    ///
+    /// assert_eq!(matrix.iter().sum::<f32>(), 10.0);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.body.iter().flatten()
+    }
+
+    /// Returns a mutable iterator over the elements of the matrix,
+    /// in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # pub use small_matrix::matrix::Matrix;
+    /// let mut matrix = Matrix::<f32, 2, 2>::fill(1.0);
+    ///
+    /// matrix.iter_mut().for_each(|e| *e += 1.0);
+    ///
+    /// assert_eq!(matrix[(0, 0)], 2.0);
+    /// ```
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.body.iter_mut().flatten()
+    }
+
+    /// Returns an iterator over the `(row, column)` indices of the
+    /// matrix, in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # pub use small_matrix::matrix::Matrix;
+    /// let matrix = Matrix::<f32, 2, 2>::zeros();
+    ///
+    /// let indices: Vec<(usize, usize)> = matrix.indices().collect();
+    ///
+    /// assert_eq!(indices, vec![(0, 0), (0, 1), (1, 0), (1, 1)]);
+    /// ```
+    pub fn indices(&self) -> impl Iterator<Item = (usize, usize)> {
+        (0..M).flat_map(|r| (0..N).map(move |c| (r, c)))
+    }
+
+    /// Returns an iterator over the rows of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # pub use small_matrix::matrix::Matrix;
+    /// let matrix = Matrix::new([
+    ///     [1.0, 2.0],
+    ///     [3.0, 4.0],
+    /// ]);
+    ///
+    /// assert_eq!(matrix.rows().next(), Some([1.0, 2.0]));
+    /// ```
+    pub fn rows(&self) -> impl Iterator<Item = [T; N]> + '_ {
+        self.body.iter().copied()
+    }
+
+    /// Returns an iterator over the columns of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # pub use small_matrix::matrix::Matrix;
+    /// let matrix = Matrix::new([
+    ///     [1.0, 2.0],
+    ///     [3.0, 4.0],
+    /// ]);
+    ///
+    /// assert_eq!(matrix.cols().next(), Some([1.0, 3.0]));
+    /// ```
+    pub fn cols(&self) -> impl Iterator<Item = [T; M]> + '_ {
+        (0..N).map(move |c| self.body.map(|row| row[c]))
+    }
+}
+
+impl<T: Scalar, const M: usize, const N: usize> IntoIterator for Matrix<T, M, N> {
+    type Item = T;
+    type IntoIter = std::iter::Flatten<std::array::IntoIter<[T; N], M>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.body.into_iter().flatten()
+    }
+}
+
+impl<'a, T: Scalar, const M: usize, const N: usize> IntoIterator for &'a Matrix<T, M, N> {
+    type Item = &'a T;
+    type IntoIter = std::iter::Flatten<std::slice::Iter<'a, [T; N]>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.body.iter().flatten()
+    }
+}
+
+impl<'a, T: Scalar, const M: usize, const N: usize> IntoIterator for &'a mut Matrix<T, M, N> {
+    type Item = &'a mut T;
+    type IntoIter = std::iter::Flatten<std::slice::IterMut<'a, [T; N]>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.body.iter_mut().flatten()
+    }
+}
+
+impl<T: Scalar, const N: usize> Matrix<T, N, N> {
+    /// Returns the identity matrix of size `NxN`, the generic
+    /// replacement for the old hard-coded `I_2`/`I_3`/`I_4`
+    /// constants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # pub use small_matrix::matrix::Matrix;
+    /// let identity = Matrix::<f32, 3, 3>::identity();
+    ///
+    /// assert_eq!(identity[(1, 1)], 1.0);
+    /// assert_eq!(identity[(0, 1)], 0.0);
+    /// ```
+    pub fn identity() -> Self {
+        let mut body = [[T::zero(); N]; N];
+
+        body.iter_mut().enumerate().for_each(|(i, row)| row[i] = T::one());
+
+        Self { body }
+    }
+}
+
+impl<T: Scalar + ops::Neg<Output = T>> Matrix<T, 2, 2> {
+    /// Returns the `90°` rotation matrix `2x2`, the generic
+    /// replacement for the old hard-coded `f32`-only `R90_2`
+    /// constant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # pub use small_matrix::matrix::Matrix;
+    /// let matrix = Matrix::new([
+    ///     [1.0],
+    ///     [2.0],
+    /// ]);
+    ///
+    /// assert_eq!(Matrix::rot90() * matrix, Matrix::new([[-2.0], [1.0]]));
+    /// ```
+    pub fn rot90() -> Self {
+        Matrix::new([
+            [T::zero(), -T::one()],
+            [T::one(), T::zero()],
+        ])
+    }
+
+    /// Returns the `180°` rotation matrix `2x2`, the generic
+    /// replacement for the old hard-coded `f32`-only `R180_2`
+    /// constant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # pub use small_matrix::matrix::Matrix;
+    /// let matrix = Matrix::new([
+    ///     [1.0],
+    ///     [2.0],
+    /// ]);
+    ///
+    /// assert_eq!(Matrix::rot180() * matrix, Matrix::new([[-1.0], [-2.0]]));
+    /// ```
+    pub fn rot180() -> Self {
+        Matrix::new([
+            [-T::one(), T::zero()],
+            [T::zero(), -T::one()],
+        ])
+    }
+
+    /// Returns the `270°` rotation matrix `2x2`, the generic
+    /// replacement for the old hard-coded `f32`-only `R270_2`
+    /// constant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # pub use small_matrix::matrix::Matrix;
+    /// let matrix = Matrix::new([
+    ///     [1.0],
+    ///     [2.0],
+    /// ]);
+    ///
+    /// assert_eq!(Matrix::rot270() * matrix, Matrix::new([[2.0], [-1.0]]));
+    /// ```
+    pub fn rot270() -> Self {
+        Matrix::new([
+            [T::zero(), T::one()],
+            [-T::one(), T::zero()],
+        ])
+    }
+}
+
+impl<T: Float, const N: usize> Matrix<T, N, N> {
+    fn abs(x: T) -> T {
+        if x < T::zero() { -x } else { x }
+    }
+
+    /// Decomposes the matrix into a lower-triangular `L` (with a
+    /// unit diagonal), an upper-triangular `U`, a row permutation
+    /// and a sign, via Gaussian elimination with partial pivoting.
+    ///
+    /// `det`, `inverse` and `solve` are all built on top of this
+    /// routine.
+    fn lu(&self) -> (Matrix<T, N, N>, Matrix<T, N, N>, [usize; N], T) {
+        let mut u = *self;
+        let mut l = Matrix::<T, N, N>::identity();
+        let mut perm = [0usize; N];
+        perm.iter_mut().enumerate().for_each(|(i, p)| *p = i);
+        let mut sign = T::one();
+
+        for k in 0..N {
+            let mut pivot_row = k;
+            let mut pivot_val = Self::abs(u.body[k][k]);
+
+            for i in (k + 1)..N {
+                let val = Self::abs(u.body[i][k]);
+
+                if val > pivot_val {
+                    pivot_val = val;
+                    pivot_row = i;
+                }
+            }
+
+            if pivot_row != k {
+                u.swap_rows(k, pivot_row);
+                perm.swap(k, pivot_row);
+                sign = -sign;
+
+                for j in 0..k {
+                    let tmp = l.body[k][j];
+                    l.body[k][j] = l.body[pivot_row][j];
+                    l.body[pivot_row][j] = tmp;
+                }
+            }
+
+            if Self::abs(u.body[k][k]) <= T::epsilon() {
+                continue;
+            }
+
+            for i in (k + 1)..N {
+                let factor = u.body[i][k] / u.body[k][k];
+                l.body[i][k] = factor;
+
+                for j in k..N {
+                    u.body[i][j] = u.body[i][j] - factor * u.body[k][j];
+                }
+            }
+        }
+
+        (l, u, perm, sign)
+    }
+
+    /// Returns the determinant of the matrix, computed from its
+    /// `LU` decomposition as `sign * product(diag(U))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # pub use small_matrix::matrix::Matrix;
+    /// let matrix = Matrix::new([
+    ///     [1.0, 2.0],
+    ///     [3.0, 4.0],
+    /// ]);
+    ///
+    /// assert_eq!(matrix.det(), -2.0);
+    /// ```
+    pub fn det(&self) -> T {
+        let (_, u, _, sign) = self.lu();
+
+        (0..N).fold(sign, |acc, i| acc * u.body[i][i])
+    }
+
+    /// Solves the linear system `self * x = b` for `x`, via
+    /// forward and back substitution through the `LU`
+    /// decomposition of `self`. Returns `None` if `self` is
+    /// singular.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # pub use small_matrix::matrix::Matrix;
+    /// let matrix = Matrix::new([
+    ///     [1.0, 2.0],
+    ///     [3.0, 4.0],
+    /// ]);
+    ///
+    /// let b = Matrix::new([[5.0], [6.0]]);
+    ///
+    /// let x = matrix.solve(b).unwrap();
+    ///
+    /// assert_eq!(matrix * x, b);
+    /// ```
+    pub fn solve(&self, b: Matrix<T, N, 1>) -> Option<Matrix<T, N, 1>> {
+        let (l, u, perm, _) = self.lu();
+
+        if (0..N).any(|i| Self::abs(u.body[i][i]) <= T::epsilon()) {
+            return None;
+        }
+
+        let mut y = [T::zero(); N];
+
+        for i in 0..N {
+            let mut sum = b.body[perm[i]][0];
+
+            for (j, &yj) in y.iter().enumerate().take(i) {
+                sum = sum - l.body[i][j] * yj;
+            }
+
+            y[i] = sum;
+        }
+
+        let mut x = [T::zero(); N];
+
+        for i in (0..N).rev() {
+            let mut sum = y[i];
+
+            for (j, &xj) in x.iter().enumerate().take(N).skip(i + 1) {
+                sum = sum - u.body[i][j] * xj;
+            }
+
+            x[i] = sum / u.body[i][i];
+        }
+
+        Some(Matrix::new(x.map(|v| [v])))
+    }
+
+    /// Returns the inverse of the matrix, solving `self * X = I`
+    /// one column at a time. Returns `None` if `self` is singular.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # pub use small_matrix::matrix::Matrix;
+    /// let matrix = Matrix::new([
+    ///     [2.0, 0.0],
+    ///     [0.0, 4.0],
+    /// ]);
+    ///
+    /// let inverse = matrix.inverse().unwrap();
+    ///
+    /// assert_eq!(matrix * inverse, Matrix::<f32, 2, 2>::identity());
+    /// ```
+    pub fn inverse(&self) -> Option<Matrix<T, N, N>> {
+        let mut body = [[T::zero(); N]; N];
+
+        for j in 0..N {
+            let mut e = [[T::zero(); 1]; N];
+            e[j][0] = T::one();
+
+            let column = self.solve(Matrix::new(e))?;
+
+            for (i, row) in body.iter_mut().enumerate() {
+                row[j] = column.body[i][0];
+            }
+        }
+
+        Some(Matrix { body })
+    }
+}
+
+/// A column vector of `N` elements, i.e. an `Nx1` matrix.
+pub type Vector<T, const N: usize> = Matrix<T, N, 1>;
+
+/// A row vector of `N` elements, i.e. a `1xN` matrix.
+pub type RowVector<T, const N: usize> = Matrix<T, 1, N>;
+
+impl<T: Scalar, const N: usize> Vector<T, N> {
+    /// Returns the dot product of `self` and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # pub use small_matrix::matrix::Vector;
+    /// let a = Vector::new([[1.0], [2.0], [3.0]]);
+    /// let b = Vector::new([[4.0], [5.0], [6.0]]);
+    ///
+    /// assert_eq!(a.dot(&b), 32.0);
+    /// ```
+    pub fn dot(&self, other: &Self) -> T {
+        self.iter().zip(other.iter()).fold(T::zero(), |acc, (&a, &b)| acc + a * b)
+    }
+
+    /// Returns the squared norm (length) of the vector, avoiding
+    /// the square root in [`Vector::norm`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # pub use small_matrix::matrix::Vector;
+    /// let v = Vector::new([[3.0], [4.0]]);
+    ///
+    /// assert_eq!(v.norm_squared(), 25.0);
+    /// ```
+    pub fn norm_squared(&self) -> T {
+        self.dot(self)
+    }
+}
+
+impl<T: Float, const N: usize> Vector<T, N> {
+    /// Returns the norm (length) of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # pub use small_matrix::matrix::Vector;
+    /// let v = Vector::new([[3.0], [4.0]]);
+    ///
+    /// assert_eq!(v.norm(), 5.0);
+    /// ```
+    pub fn norm(&self) -> T {
+        self.norm_squared().sqrt()
+    }
+
+    /// Returns `self` scaled to unit length, or `None` if its norm
+    /// is within [`Scalar::epsilon`] of zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # pub use small_matrix::matrix::Vector;
+    /// let v = Vector::new([[3.0], [4.0]]);
+    ///
+    /// assert_eq!(v.normalize().unwrap().norm(), 1.0);
+    /// assert_eq!(Vector::<f32, 2>::zeros().normalize(), None);
+    /// ```
+    pub fn normalize(&self) -> Option<Self> {
+        let norm = self.norm();
+
+        if norm <= T::epsilon() {
+            None
+        } else {
+            Some(*self / norm)
+        }
+    }
+}
+
+impl<T: Scalar> Vector<T, 3> {
+    /// Returns the cross product of `self` and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # pub use small_matrix::matrix::Vector;
+    /// let x = Vector::new([[1.0], [0.0], [0.0]]);
+    /// let y = Vector::new([[0.0], [1.0], [0.0]]);
+    ///
+    /// assert_eq!(x.cross(&y), Vector::new([[0.0], [0.0], [1.0]]));
+    /// ```
+    pub fn cross(&self, other: &Self) -> Self {
+        let [x1, y1, z1] = [self[(0, 0)], self[(1, 0)], self[(2, 0)]];
+        let [x2, y2, z2] = [other[(0, 0)], other[(1, 0)], other[(2, 0)]];
+
+        Vector::new([
+            [y1 * z2 - z1 * y2],
+            [z1 * x2 - x1 * z2],
+            [x1 * y2 - y1 * x2],
+        ])
+    }
+}
+
+impl<T: Scalar + fmt::Debug, const M: usize, const N: usize> fmt::Display for Matrix<T, M, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.body.iter().try_for_each(|row| writeln!(f, "{:?}", row))
+    }
+}
+
+/// A macro used to implement `Add` and `Sub`.
+macro_rules! impl_ops {
+    ($trait:ident, $func:ident, $op:tt) => {
+        impl<T: Scalar, const M: usize, const N: usize> ops::$trait for Matrix<T, M, N> {
+            type Output = Self;
+
+            fn $func(self, other: Self) -> Self {
+                let mut result = Self::zeros();
+
+                result.iter_mut().zip(self.iter().zip(other.iter())).for_each(|(r, (s, o))| *r = *s $op *o);
+
+                result
+            }
+        }
+    };
+}
+
+impl_ops!(Add, add, +);
+impl_ops!(Sub, sub, -);
+
+/// A macro used to implement the `&Matrix op Matrix`, `Matrix op
+/// &Matrix` and `&Matrix op &Matrix` permutations of `Add` and
+/// `Sub` in terms of the owned `Matrix op Matrix` impl, so callers
+/// don't have to copy large matrices just to chain expressions on
+/// borrowed operands.
+macro_rules! impl_ops_ref {
+    ($trait:ident, $func:ident) => {
+        impl<T: Scalar, const M: usize, const N: usize> ops::$trait<Matrix<T, M, N>> for &Matrix<T, M, N> {
+            type Output = Matrix<T, M, N>;
+
+            fn $func(self, other: Matrix<T, M, N>) -> Matrix<T, M, N> {
+                ops::$trait::$func(*self, other)
+            }
+        }
+
+        impl<T: Scalar, const M: usize, const N: usize> ops::$trait<&Matrix<T, M, N>> for Matrix<T, M, N> {
+            type Output = Matrix<T, M, N>;
+
+            fn $func(self, other: &Matrix<T, M, N>) -> Matrix<T, M, N> {
+                ops::$trait::$func(self, *other)
+            }
+        }
+
+        impl<T: Scalar, const M: usize, const N: usize> ops::$trait<&Matrix<T, M, N>> for &Matrix<T, M, N> {
+            type Output = Matrix<T, M, N>;
+
+            fn $func(self, other: &Matrix<T, M, N>) -> Matrix<T, M, N> {
+                ops::$trait::$func(*self, *other)
+            }
+        }
+    };
+}
+
+impl_ops_ref!(Add, add);
+impl_ops_ref!(Sub, sub);
+
+impl<T: Scalar, const M: usize, const L: usize, const N: usize> ops::Mul<Matrix<T, L, N>> for Matrix<T, M, L> {
+    type Output = Matrix<T, M, N>;
+
+    fn mul(self, other: Matrix<T, L, N>) -> Matrix<T, M, N> {
+        let mut body = [[T::zero(); N]; M];
+
+        let other_t = other.transpose();
+
+        self.rows().enumerate().for_each(|(i, row)| {
+            other_t.rows().enumerate().for_each(|(j, col)| {
+                body[i][j] = row.iter().zip(&col).fold(T::zero(), |acc, (s, o)| acc + *s * *o);
+            });
+        });
+
+        Matrix { body }
+    }
+}
+
+impl<T: Scalar, const M: usize, const L: usize, const N: usize> ops::Mul<Matrix<T, L, N>> for &Matrix<T, M, L> {
+    type Output = Matrix<T, M, N>;
+
+    fn mul(self, other: Matrix<T, L, N>) -> Matrix<T, M, N> {
+        *self * other
+    }
+}
+
+impl<T: Scalar, const M: usize, const L: usize, const N: usize> ops::Mul<&Matrix<T, L, N>> for Matrix<T, M, L> {
+    type Output = Matrix<T, M, N>;
+
+    fn mul(self, other: &Matrix<T, L, N>) -> Matrix<T, M, N> {
+        self * *other
+    }
+}
+
+impl<T: Scalar, const M: usize, const L: usize, const N: usize> ops::Mul<&Matrix<T, L, N>> for &Matrix<T, M, L> {
+    type Output = Matrix<T, M, N>;
+
+    fn mul(self, other: &Matrix<T, L, N>) -> Matrix<T, M, N> {
+        *self * *other
+    }
+}
+
+/// A macro used to implement
+/// `AddAssign` and `SubAssign`.
+macro_rules! impl_ops_assign {
+    ($trait_assign:ident, $func_assign:ident, $op_assign:tt) => {
+        impl<T: Scalar + ops::$trait_assign, const M: usize, const N: usize> ops::$trait_assign for Matrix<T, M, N> {
+            fn $func_assign(&mut self, other: Self) {
+                self.iter_mut().zip(other.iter()).for_each(|(s, o)| *s $op_assign *o);
+            }
+        }
+    };
+}
+
+impl_ops_assign!(AddAssign, add_assign, +=);
+impl_ops_assign!(SubAssign, sub_assign, -=);
+
+/// A macro used to implement
+/// `Add<T>`, `Sub<T>`,
+/// `Mul<T>` and `Div<T>`.
+macro_rules! impl_ops_scalar {
+    ($trait:ident, $func:ident, $op:tt) => {
+        impl<T: Scalar + ops::$trait<Output = T>, const M: usize, const N: usize> ops::$trait<T> for Matrix<T, M, N> {
+            type Output = Self;
+
+            fn $func(self, other: T) -> Self {
+                let mut result = Self::zeros();
+
+                result.iter_mut().zip(self.iter()).for_each(|(r, s)| *r = *s $op other);
+
+                result
+            }
+        }
+    };
+}
+
+impl_ops_scalar!(Add, add, +);
+impl_ops_scalar!(Sub, sub, -);
+impl_ops_scalar!(Mul, mul, *);
+impl_ops_scalar!(Div, div, /);
+
+/// A macro used to implement `&Matrix op T` in terms of the owned
+/// `Matrix op T` impl, for `Add<T>`, `Sub<T>`, `Mul<T>` and
+/// `Div<T>`.
+macro_rules! impl_ops_scalar_ref {
+    ($trait:ident, $func:ident) => {
+        impl<T: Scalar + ops::$trait<Output = T>, const M: usize, const N: usize> ops::$trait<T> for &Matrix<T, M, N> {
+            type Output = Matrix<T, M, N>;
+
+            fn $func(self, other: T) -> Matrix<T, M, N> {
+                ops::$trait::$func(*self, other)
+            }
+        }
+    };
+}
+
+impl_ops_scalar_ref!(Add, add);
+impl_ops_scalar_ref!(Sub, sub);
+impl_ops_scalar_ref!(Mul, mul);
+impl_ops_scalar_ref!(Div, div);
+
+/// A macro used to implement
+/// `AddAssign<T>`, `SubAssign<T>`,
+/// `MulAssign<T>` and `DivAssign<T>`.
+macro_rules! impl_ops_assign_scalar {
+    ($trait_assign:ident, $func_assign:ident, $op_assign:tt) => {
+        impl<T: Scalar + ops::$trait_assign, const M: usize, const N: usize> ops::$trait_assign<T> for Matrix<T, M, N> {
+            fn $func_assign(&mut self, other: T) {
+                self.iter_mut().for_each(|e| *e $op_assign other);
+            }
+        }
+    };
+}
+
+impl_ops_assign_scalar!(AddAssign, add_assign, +=);
+impl_ops_assign_scalar!(SubAssign, sub_assign, -=);
+impl_ops_assign_scalar!(MulAssign, mul_assign, *=);
+impl_ops_assign_scalar!(DivAssign, div_assign, /=);
+
+impl<T: Scalar, const M: usize, const N: usize> ops::Index<(usize, usize)> for Matrix<T, M, N> {
+    type Output = T;
+
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    fn index(&self, idx: (usize, usize)) -> &T {
+        let (r, c) = idx.to_2d(M, N).expect("index out of bounds");
+        &self.body[r][c]
+    }
+}
+
+impl<T: Scalar, const M: usize, const N: usize> ops::IndexMut<(usize, usize)> for Matrix<T, M, N> {
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    fn index_mut(&mut self, idx: (usize, usize)) -> &mut T {
+        let (r, c) = idx.to_2d(M, N).expect("index out of bounds");
+        &mut self.body[r][c]
+    }
+}
+
+impl<T: Scalar, const M: usize, const N: usize> ops::Index<usize> for Matrix<T, M, N> {
+    type Output = [T; N];
+
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    fn index(&self, idx: usize) -> &[T; N] {
+        &self.body[idx]
+    }
+}
+
+impl<T: Scalar, const M: usize, const N: usize> ops::IndexMut<usize> for Matrix<T, M, N> {
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    fn index_mut(&mut self, idx: usize) -> &mut [T; N] {
+        &mut self.body[idx]
+    }
+}
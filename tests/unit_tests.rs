@@ -9,8 +9,10 @@ mod tests {
             [3.0, 4.0],
         ]);
 
-        assert_eq!(matrix * I_2, I_2 * matrix);
-        assert_eq!(matrix * I_2, matrix);
+        let identity = Matrix::<f32, 2, 2>::identity();
+
+        assert_eq!(matrix * identity, identity * matrix);
+        assert_eq!(matrix * identity, matrix);
     }
 
     #[test]
@@ -21,8 +23,10 @@ mod tests {
             [7.0, 8.0, 9.0],
         ]);
 
-        assert_eq!(matrix * I_3, I_3 * matrix);
-        assert_eq!(matrix * I_3, matrix);
+        let identity = Matrix::<f32, 3, 3>::identity();
+
+        assert_eq!(matrix * identity, identity * matrix);
+        assert_eq!(matrix * identity, matrix);
     }
 
     #[test]
@@ -34,12 +38,171 @@ mod tests {
             [13.0, 14.0, 15.0, 16.0],
         ]);
 
-        assert_eq!(matrix * I_4, I_4 * matrix);
-        assert_eq!(matrix * I_4, matrix);
+        let identity = Matrix::<f32, 4, 4>::identity();
+
+        assert_eq!(matrix * identity, identity * matrix);
+        assert_eq!(matrix * identity, matrix);
+    }
+
+    #[test]
+    fn generic_integer_matrix_test() {
+        let matrix = Matrix::new([
+            [1, 2],
+            [3, 4],
+        ]);
+
+        let identity = Matrix::<i32, 2, 2>::identity();
+
+        assert_eq!(matrix * identity, matrix);
+    }
+
+    #[test]
+    fn index_2d_test() {
+        let mut matrix = Matrix::new([
+            [1.0, 2.0],
+            [3.0, 4.0],
+        ]);
+
+        assert_eq!(matrix[(0, 1)], 2.0);
+        assert_eq!(matrix[1], [3.0, 4.0]);
+
+        matrix[(0, 1)] = 7.0;
+
+        assert_eq!(matrix[(0, 1)], 7.0);
+    }
+
+    #[test]
+    fn det_test() {
+        let matrix = Matrix::new([
+            [1.0, 2.0],
+            [3.0, 4.0],
+        ]);
+
+        assert_eq!(matrix.det(), -2.0);
+    }
+
+    #[test]
+    fn singular_det_test() {
+        let matrix = Matrix::new([
+            [1.0, 2.0],
+            [2.0, 4.0],
+        ]);
+
+        assert_eq!(matrix.det(), 0.0);
+        assert_eq!(matrix.inverse(), None);
+    }
+
+    #[test]
+    fn inverse_test() {
+        let matrix = Matrix::new([
+            [4.0, 7.0],
+            [2.0, 6.0],
+        ]);
+
+        let inverse = matrix.inverse().unwrap();
+        let product = matrix * inverse;
+
+        // Compares loosely since the LU-based inverse accumulates
+        // floating-point rounding error that exact equality wouldn't tolerate.
+        product.iter().zip(Matrix::<f32, 2, 2>::identity().iter())
+            .for_each(|(a, b)| assert!((a - b).abs() < 1e-5));
+    }
+
+    #[test]
+    fn solve_test() {
+        let matrix = Matrix::new([
+            [3.0, 2.0],
+            [1.0, 2.0],
+        ]);
+
+        let b = Matrix::new([[5.0], [3.0]]);
+
+        let x = matrix.solve(b).unwrap();
+
+        assert_eq!(matrix * x, b);
+    }
+
+    #[test]
+    fn iter_test() {
+        let matrix = Matrix::new([
+            [1.0, 2.0],
+            [3.0, 4.0],
+        ]);
+
+        assert_eq!(matrix.iter().sum::<f32>(), 10.0);
+        assert_eq!(matrix.indices().count(), 4);
+        assert_eq!(matrix.rows().collect::<Vec<_>>(), vec![[1.0, 2.0], [3.0, 4.0]]);
+        assert_eq!(matrix.cols().collect::<Vec<_>>(), vec![[1.0, 3.0], [2.0, 4.0]]);
+    }
+
+    #[test]
+    fn into_iter_test() {
+        let mut matrix = Matrix::new([
+            [1.0, 2.0],
+            [3.0, 4.0],
+        ]);
+
+        for e in &mut matrix {
+            *e += 1.0;
+        }
+
+        assert_eq!((&matrix).into_iter().copied().sum::<f32>(), 14.0);
+        assert_eq!(matrix.into_iter().collect::<Vec<_>>(), vec![2.0, 3.0, 4.0, 5.0]);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn reference_ops_test() {
+        let a = Matrix::new([
+            [1.0, 2.0],
+            [3.0, 4.0],
+        ]);
+
+        let b = Matrix::new([
+            [4.0, 3.0],
+            [2.0, 1.0],
+        ]);
+
+        let sum = Matrix::new([
+            [5.0, 5.0],
+            [5.0, 5.0],
+        ]);
+
+        assert_eq!(&a + &b, sum);
+        assert_eq!(a + &b, sum);
+        assert_eq!(&a + b, sum);
+        assert_eq!(&a * 2.0, a * 2.0);
+        assert_eq!(&a * &b, a * b);
+    }
+
+    #[test]
+    fn dot_product_test() {
+        let a = Vector::new([[1.0], [2.0], [3.0]]);
+        let b = Vector::new([[4.0], [5.0], [6.0]]);
+
+        assert_eq!(a.dot(&b), 32.0);
+    }
+
+    #[test]
+    fn norm_and_normalize_test() {
+        let v = Vector::new([[3.0], [4.0]]);
+
+        assert_eq!(v.norm_squared(), 25.0);
+        assert_eq!(v.norm(), 5.0);
+        assert_eq!(v.normalize().unwrap().norm(), 1.0);
+        assert_eq!(Vector::<f32, 2>::zeros().normalize(), None);
+    }
+
+    #[test]
+    fn cross_product_test() {
+        let x = Vector::new([[1.0], [0.0], [0.0]]);
+        let y = Vector::new([[0.0], [1.0], [0.0]]);
+
+        assert_eq!(x.cross(&y), Vector::new([[0.0], [0.0], [1.0]]));
     }
 
     #[test]
-    fn r90_2_test() {        
+    fn r90_2_test() {
         let matrix = Matrix::new([
             [1.0],
             [2.0],
@@ -50,7 +213,7 @@ mod tests {
             [ 1.0],
         ]);
 
-        assert_eq!(R90_2 * matrix, rotated);
+        assert_eq!(Matrix::rot90() * matrix, rotated);
     }
 
     #[test]
@@ -65,7 +228,7 @@ mod tests {
             [-2.0],
         ]);
 
-        assert_eq!(R180_2 * matrix, rotated);
+        assert_eq!(Matrix::rot180() * matrix, rotated);
     }
 
     #[test]
@@ -80,7 +243,7 @@ mod tests {
             [-1.0],
         ]);
 
-        assert_eq!(R270_2 * matrix, rotated);
+        assert_eq!(Matrix::rot270() * matrix, rotated);
     }
 
     #[test]